@@ -20,10 +20,12 @@ use deno_semver::npm::NpmPackageReqReference;
 use indexmap::IndexMap;
 use jsonc_parser::ast::ObjectProp;
 use jsonc_parser::ast::Value;
+use tokio::io::AsyncReadExt;
 
 use crate::args::AddFlags;
 use crate::args::CacheSetting;
 use crate::args::Flags;
+use crate::args::RemoveFlags;
 use crate::factory::CliFactory;
 use crate::file_fetcher::FileFetcher;
 use crate::jsr::JsrFetchResolver;
@@ -62,8 +64,13 @@ impl DenoOrPackageJson {
     }
   }
 
-  /// Returns the existing imports/dependencies from the config.
-  fn existing_imports(&self) -> Result<IndexMap<String, String>, AnyError> {
+  /// Returns the existing imports/dependencies from the config, for the
+  /// regular or dev section depending on `dev`. `deno.json` has no separate
+  /// dev import group, so `dev` is ignored there.
+  fn existing_imports(
+    &self,
+    dev: bool,
+  ) -> Result<IndexMap<String, String>, AnyError> {
     match self {
       DenoOrPackageJson::Deno(deno, ..) => {
         if let Some(imports) = deno.json.imports.clone() {
@@ -78,7 +85,11 @@ impl DenoOrPackageJson {
         }
       }
       DenoOrPackageJson::Npm(npm, ..) => {
-        Ok(npm.dependencies.clone().unwrap_or_default())
+        if dev {
+          Ok(npm.dev_dependencies.clone().unwrap_or_default())
+        } else {
+          Ok(npm.dependencies.clone().unwrap_or_default())
+        }
       }
     }
   }
@@ -95,11 +106,12 @@ impl DenoOrPackageJson {
     }
   }
 
-  fn imports_key(&self) -> &'static str {
-    match self {
-      DenoOrPackageJson::Deno(..) => "imports",
-      DenoOrPackageJson::Npm(..) => "dependencies",
-    }
+  /// Returns the key of the section new entries should be written into.
+  /// `package.json` targets honor `dev`, writing into `devDependencies`
+  /// instead of `dependencies`; `deno.json` has no separate dev import
+  /// group, so `dev` is ignored there.
+  fn imports_key(&self, dev: bool) -> &'static str {
+    imports_key_for(self.is_npm(), dev)
   }
 
   fn file_name(&self) -> &'static str {
@@ -162,6 +174,35 @@ impl DenoOrPackageJson {
   }
 }
 
+/// Pure `--dev` routing logic behind `imports_key`, split out so it can be
+/// unit tested without constructing a full `DenoOrPackageJson`.
+fn imports_key_for(is_npm: bool, dev: bool) -> &'static str {
+  if !is_npm {
+    "imports"
+  } else if dev {
+    "devDependencies"
+  } else {
+    "dependencies"
+  }
+}
+
+#[cfg(test)]
+mod imports_key_for_tests {
+  use super::imports_key_for;
+
+  #[test]
+  fn deno_json_ignores_dev() {
+    assert_eq!(imports_key_for(false, false), "imports");
+    assert_eq!(imports_key_for(false, true), "imports");
+  }
+
+  #[test]
+  fn package_json_routes_dev_to_dev_dependencies() {
+    assert_eq!(imports_key_for(true, false), "dependencies");
+    assert_eq!(imports_key_for(true, true), "devDependencies");
+  }
+}
+
 fn package_json_dependency_entry(
   selected: SelectedPackage,
 ) -> (String, String) {
@@ -178,6 +219,65 @@ fn package_json_dependency_entry(
   }
 }
 
+/// Resolves the list of package specs an `add` invocation should operate on:
+/// the `--from-file <path>` manifest, newline-separated specs from stdin
+/// (`deno add -`), or the packages given directly on the command line.
+async fn resolve_package_specs(
+  add_flags: &AddFlags,
+) -> Result<Vec<String>, AnyError> {
+  if let Some(path) = &add_flags.from_file {
+    let contents = tokio::fs::read_to_string(path)
+      .await
+      .with_context(|| format!("Failed to read {}", path))?;
+    return Ok(parse_package_specs(&contents));
+  }
+
+  if add_flags.packages == ["-"] {
+    let mut contents = String::new();
+    tokio::io::stdin()
+      .read_to_string(&mut contents)
+      .await
+      .context("Failed to read package specs from stdin")?;
+    return Ok(parse_package_specs(&contents));
+  }
+
+  Ok(add_flags.packages.clone())
+}
+
+/// Parses a newline-separated list of package specs, such as
+/// `jsr:@std/fs@^1` or `npm:chalk`, skipping blank lines and `#` comments.
+fn parse_package_specs(text: &str) -> Vec<String> {
+  text
+    .lines()
+    .map(|line| line.trim())
+    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+    .map(|line| line.to_string())
+    .collect()
+}
+
+#[cfg(test)]
+mod parse_package_specs_tests {
+  use super::parse_package_specs;
+
+  #[test]
+  fn skips_blank_lines_and_comments() {
+    let text = "jsr:@std/fs@^1\n\n# a comment\nnpm:chalk\n";
+    assert_eq!(
+      parse_package_specs(text),
+      vec!["jsr:@std/fs@^1".to_string(), "npm:chalk".to_string()]
+    );
+  }
+
+  #[test]
+  fn trims_surrounding_whitespace() {
+    let text = "  npm:chalk  \n\tjsr:@std/fs@^1\t\n";
+    assert_eq!(
+      parse_package_specs(text),
+      vec!["npm:chalk".to_string(), "jsr:@std/fs@^1".to_string()]
+    );
+  }
+}
+
 pub async fn add(flags: Flags, add_flags: AddFlags) -> Result<(), AnyError> {
   let (config_file, cli_factory) =
     DenoOrPackageJson::from_flags(flags.clone())?;
@@ -190,10 +290,12 @@ pub async fn add(flags: Flags, add_flags: AddFlags) -> Result<(), AnyError> {
 
   let http_client = cli_factory.http_client_provider();
 
-  let mut selected_packages = Vec::with_capacity(add_flags.packages.len());
-  let mut package_reqs = Vec::with_capacity(add_flags.packages.len());
+  let package_specs = resolve_package_specs(&add_flags).await?;
+
+  let mut selected_packages = Vec::with_capacity(package_specs.len());
+  let mut package_reqs = Vec::with_capacity(package_specs.len());
 
-  for package_name in add_flags.packages.iter() {
+  for package_name in package_specs.iter() {
     let req = if package_name.starts_with("npm:") {
       let pkg_req = NpmPackageReqReference::from_str(&format!(
         "npm:{}",
@@ -246,12 +348,13 @@ pub async fn add(flags: Flags, add_flags: AddFlags) -> Result<(), AnyError> {
   let stream_of_futures = deno_core::futures::stream::iter(package_futures);
   let mut buffered = stream_of_futures.buffer_unordered(10);
 
+  let mut not_found_packages = Vec::new();
   while let Some(package_and_version_result) = buffered.next().await {
     let package_and_version = package_and_version_result?;
 
     match package_and_version {
       PackageAndVersion::NotFound(package_name) => {
-        bail!("{} was not found.", crate::colors::red(package_name));
+        not_found_packages.push(package_name);
       }
       PackageAndVersion::Selected(selected) => {
         selected_packages.push(selected);
@@ -259,6 +362,22 @@ pub async fn add(flags: Flags, add_flags: AddFlags) -> Result<(), AnyError> {
     }
   }
 
+  if !not_found_packages.is_empty() {
+    bail!(
+      "{} {} not found.",
+      not_found_packages
+        .iter()
+        .map(|package_name| crate::colors::red(package_name).to_string())
+        .collect::<Vec<_>>()
+        .join(", "),
+      if not_found_packages.len() == 1 {
+        "was"
+      } else {
+        "were"
+      }
+    );
+  }
+
   let config_file_contents = {
     let contents = tokio::fs::read_to_string(&config_file_path).await.unwrap();
     if contents.trim().is_empty() {
@@ -278,7 +397,10 @@ pub async fn add(flags: Flags, add_flags: AddFlags) -> Result<(), AnyError> {
     _ => bail!("Failed updating config file due to no object."),
   };
 
-  let mut existing_imports = config_file.existing_imports()?;
+  // Only the entries being added are collected here - existing entries are
+  // left untouched so their comments and ordering survive the rewrite (see
+  // `imports_text_changes`).
+  let mut new_entries: IndexMap<String, String> = IndexMap::new();
 
   let is_npm = config_file.is_npm();
   for selected_package in selected_packages {
@@ -291,9 +413,9 @@ pub async fn add(flags: Flags, add_flags: AddFlags) -> Result<(), AnyError> {
 
     if is_npm {
       let (name, version) = package_json_dependency_entry(selected_package);
-      existing_imports.insert(name, version)
+      new_entries.insert(name, version)
     } else {
-      existing_imports.insert(
+      new_entries.insert(
         selected_package.import_name,
         format!(
           "{}@{}",
@@ -302,20 +424,20 @@ pub async fn add(flags: Flags, add_flags: AddFlags) -> Result<(), AnyError> {
       )
     };
   }
-  let mut import_list: Vec<(String, String)> =
-    existing_imports.into_iter().collect();
-
-  import_list.sort_by(|(k1, _), (k2, _)| k1.cmp(k2));
-  let generated_imports = generate_imports(import_list);
+  // Sort just the newly added entries so output doesn't depend on network
+  // resolution completion order - existing entries are left untouched
+  // above, so this doesn't disturb their hand-arranged ordering.
+  let mut new_entries: Vec<(String, String)> = new_entries.into_iter().collect();
+  new_entries.sort_by(|(a, _), (b, _)| a.cmp(b));
 
   let fmt_config_options = config_file.fmt_options();
 
   let new_text = update_config_file_content(
     obj,
     &config_file_contents,
-    generated_imports,
+    new_entries,
     fmt_config_options,
-    config_file.imports_key(),
+    config_file.imports_key(add_flags.dev),
     config_file.file_name(),
   );
 
@@ -335,6 +457,103 @@ pub async fn add(flags: Flags, add_flags: AddFlags) -> Result<(), AnyError> {
   Ok(())
 }
 
+/// Returns the key a package is stored under, stripping the `npm:`/`jsr:` prefix.
+fn remove_key_for_package(package_name: &str) -> &str {
+  package_name
+    .strip_prefix("npm:")
+    .or_else(|| package_name.strip_prefix("jsr:"))
+    .unwrap_or(package_name)
+}
+
+pub async fn remove(
+  flags: Flags,
+  remove_flags: RemoveFlags,
+) -> Result<(), AnyError> {
+  let (config_file, _cli_factory) = DenoOrPackageJson::from_flags(flags)?;
+
+  let config_specifier = config_file.specifier();
+  if config_specifier.scheme() != "file" {
+    bail!("Can't remove dependencies from a remote configuration file");
+  }
+  let config_file_path = config_specifier.to_file_path().unwrap();
+
+  let is_npm = config_file.is_npm();
+
+  let existing_imports = config_file.existing_imports(false)?;
+  let dev_existing_imports = if is_npm {
+    config_file.existing_imports(true)?
+  } else {
+    IndexMap::new()
+  };
+
+  // Which keys to remove from each section - only sections that actually
+  // lose an entry are touched below, so an untouched section's comments and
+  // ordering are never disturbed.
+  let mut removed_keys = vec![(config_file.imports_key(false), Vec::new())];
+  if is_npm {
+    removed_keys.push((config_file.imports_key(true), Vec::new()));
+  }
+
+  let mut not_found = vec![];
+  for package in &remove_flags.packages {
+    let key = remove_key_for_package(package);
+    let removed_regular = existing_imports.contains_key(key);
+    let removed_dev = dev_existing_imports.contains_key(key);
+
+    if removed_regular {
+      removed_keys[0].1.push(key.to_string());
+    }
+    if removed_dev {
+      removed_keys[1].1.push(key.to_string());
+    }
+    if removed_regular || removed_dev {
+      log::info!("Remove {}", crate::colors::green(package));
+    } else {
+      not_found.push(package.clone());
+    }
+  }
+
+  if !not_found.is_empty() {
+    bail!(
+      "{} {} not found in the configuration file.",
+      not_found
+        .iter()
+        .map(|package_name| crate::colors::red(package_name).to_string())
+        .collect::<Vec<_>>()
+        .join(", "),
+      if not_found.len() == 1 { "was" } else { "were" }
+    );
+  }
+
+  let config_file_contents =
+    tokio::fs::read_to_string(&config_file_path).await.unwrap();
+  let ast = jsonc_parser::parse_to_ast(
+    &config_file_contents,
+    &Default::default(),
+    &Default::default(),
+  )?;
+  let obj = match ast.value {
+    Some(Value::Object(obj)) => obj,
+    _ => bail!("Failed updating config file due to no object."),
+  };
+
+  let fmt_config_options = config_file.fmt_options();
+
+  let new_text = remove_config_file_content(
+    &obj,
+    &config_file_contents,
+    removed_keys,
+    fmt_config_options,
+    config_file.file_name(),
+  );
+
+  tokio::fs::write(&config_file_path, new_text)
+    .await
+    .context("Failed to update configuration file")?;
+
+  Ok(())
+}
+
 struct SelectedPackage {
   import_name: String,
   package_name: String,
@@ -358,15 +577,10 @@ async fn find_package_and_select_version_for_req(
       let Some(nv) = jsr_resolver.req_to_nv(req).await else {
         return Ok(PackageAndVersion::NotFound(jsr_prefixed_name));
       };
-      let range_symbol = if req.version_req.version_text().starts_with('~') {
-        '~'
-      } else {
-        '^'
-      };
       Ok(PackageAndVersion::Selected(SelectedPackage {
         import_name: req.name.to_string(),
         package_name: jsr_prefixed_name,
-        version_req: format!("{}{}", range_symbol, &nv.version),
+        version_req: version_req_to_write(&req.version_req, &nv.version)?,
       }))
     }
     AddPackageReq::Npm(pkg_ref) => {
@@ -375,20 +589,77 @@ async fn find_package_and_select_version_for_req(
       let Some(nv) = npm_resolver.req_to_nv(req).await else {
         return Ok(PackageAndVersion::NotFound(npm_prefixed_name));
       };
-      let range_symbol = if req.version_req.version_text().starts_with('~') {
-        '~'
-      } else {
-        '^'
-      };
       Ok(PackageAndVersion::Selected(SelectedPackage {
         import_name: req.name.to_string(),
         package_name: npm_prefixed_name,
-        version_req: format!("{}{}", range_symbol, &nv.version),
+        version_req: version_req_to_write(&req.version_req, &nv.version)?,
       }))
     }
   }
 }
 
+/// Returns the version requirement to write into the config file for a
+/// resolved package.
+///
+/// When the user gave an explicit version specifier, that exact text is
+/// preserved as-is, after confirming the resolver actually picked a version
+/// satisfying it - the resolver is otherwise free to pick anything it deems
+/// "latest", which would otherwise silently install a version that doesn't
+/// match what the config file claims. When the req was left unconstrained
+/// (`*`), we fall back to the existing latest-with-caret behavior.
+fn version_req_to_write(
+  version_req: &deno_semver::VersionReq,
+  resolved_version: &deno_semver::Version,
+) -> Result<String, AnyError> {
+  let version_text = version_req.version_text();
+  if version_text == "*" {
+    Ok(format!("^{}", resolved_version))
+  } else if version_req.matches(resolved_version) {
+    Ok(version_text.to_string())
+  } else {
+    bail!(
+      "Resolved version {} does not satisfy requested range {}",
+      resolved_version,
+      version_text
+    )
+  }
+}
+
+#[cfg(test)]
+mod version_req_to_write_tests {
+  use deno_semver::Version;
+  use deno_semver::VersionReq;
+
+  use super::version_req_to_write;
+
+  #[test]
+  fn preserves_exact_specifier_when_satisfied() {
+    let version_req = VersionReq::parse_from_specifier("~18.2.0").unwrap();
+    let resolved = Version::parse_standard("18.2.5").unwrap();
+    assert_eq!(
+      version_req_to_write(&version_req, &resolved).unwrap(),
+      "~18.2.0"
+    );
+  }
+
+  #[test]
+  fn falls_back_to_caret_latest_when_unconstrained() {
+    let version_req = VersionReq::parse_from_specifier("*").unwrap();
+    let resolved = Version::parse_standard("5.0.0").unwrap();
+    assert_eq!(
+      version_req_to_write(&version_req, &resolved).unwrap(),
+      "^5.0.0"
+    );
+  }
+
+  #[test]
+  fn errors_when_resolver_picked_a_version_outside_the_range() {
+    let version_req = VersionReq::parse_from_specifier("^1.0.0").unwrap();
+    let resolved = Version::parse_standard("2.0.0").unwrap();
+    assert!(version_req_to_write(&version_req, &resolved).is_err());
+  }
+}
+
 enum AddPackageReq {
   Jsr(JsrPackageReqReference),
   Npm(NpmPackageReqReference),
@@ -398,7 +669,6 @@ fn generate_imports(packages_to_version: Vec<(String, String)>) -> String {
   let mut contents = vec![];
   let len = packages_to_version.len();
   for (index, (package, version)) in packages_to_version.iter().enumerate() {
-    // TODO(bartlomieju): fix it, once we start support specifying version on the cli
     contents.push(format!("\"{}\": \"{}\"", package, version));
     if index != len - 1 {
       contents.push(",".to_string());
@@ -410,24 +680,20 @@ fn generate_imports(packages_to_version: Vec<(String, String)>) -> String {
 fn update_config_file_content(
   obj: jsonc_parser::ast::Object,
   config_file_contents: &str,
-  generated_imports: String,
+  new_entries: Vec<(String, String)>,
   fmt_options: FmtOptionsConfig,
   imports_key: &str,
   file_name: &str,
 ) -> String {
-  let mut text_changes = vec![];
-
-  match obj.get(imports_key) {
+  let text_changes = match obj.get(imports_key) {
     Some(ObjectProp {
       value: Value::Object(lit),
       ..
-    }) => text_changes.push(TextChange {
-      range: (lit.range.start + 1)..(lit.range.end - 1),
-      new_text: generated_imports,
-    }),
+    }) => imports_text_changes(lit, &new_entries),
     None => {
       let insert_position = obj.range.end - 1;
-      text_changes.push(TextChange {
+      let generated_imports = generate_imports(new_entries);
+      vec![TextChange {
         range: insert_position..insert_position,
         // NOTE(bartlomieju): adding `\n` here to force the formatter to always
         // produce a config file that is multline, like so:
@@ -438,12 +704,130 @@ fn update_config_file_content(
         //   }
         // }
         new_text: format!("\"{imports_key}\": {{\n {generated_imports} }}"),
-      })
+      }]
     }
     // we verified the shape of `imports`/`dependencies` above
     Some(_) => unreachable!(),
+  };
+
+  let new_text =
+    deno_ast::apply_text_changes(config_file_contents, text_changes);
+
+  crate::tools::fmt::format_json(
+    &PathBuf::from(file_name),
+    &new_text,
+    &fmt_options,
+  )
+  .ok()
+  .map(|formatted_text| formatted_text.unwrap_or_else(|| new_text.clone()))
+  .unwrap_or(new_text)
+}
+
+/// Computes per-key edits to an existing `imports`/`dependencies` object,
+/// leaving untouched entries byte-for-byte intact.
+fn imports_text_changes(
+  lit: &jsonc_parser::ast::Object,
+  new_entries: &[(String, String)],
+) -> Vec<TextChange> {
+  let mut text_changes = vec![];
+  let mut appended = String::new();
+  let mut is_first_append = lit.properties.is_empty();
+
+  for (key, value) in new_entries {
+    match lit.get(key) {
+      Some(prop) => text_changes.push(TextChange {
+        range: prop.value.range(),
+        new_text: format!("\"{value}\""),
+      }),
+      None => {
+        if is_first_append {
+          appended.push_str(&format!("\"{key}\": \"{value}\""));
+          is_first_append = false;
+        } else {
+          appended.push_str(&format!(",\n  \"{key}\": \"{value}\""));
+        }
+      }
+    }
+  }
+
+  if !appended.is_empty() {
+    let insert_position = lit.range.end - 1;
+    text_changes.push(TextChange {
+      range: insert_position..insert_position,
+      new_text: appended,
+    });
+  }
+
+  text_changes
+}
+
+/// Like `imports_text_changes`, but for `deno remove`: deletes the named
+/// keys, dropping the section entirely once it's empty.
+fn remove_config_file_content(
+  obj: &jsonc_parser::ast::Object,
+  config_file_contents: &str,
+  sections: Vec<(&str, Vec<String>)>,
+  fmt_options: FmtOptionsConfig,
+  file_name: &str,
+) -> String {
+  let mut text_changes = vec![];
+  let mut emptied_section_indices = Vec::new();
+
+  for (imports_key, keys) in sections {
+    if keys.is_empty() {
+      continue;
+    }
+
+    match obj.get(imports_key) {
+      Some(ObjectProp {
+        value: Value::Object(lit),
+        ..
+      }) => {
+        let remove_indices: Vec<usize> = keys
+          .iter()
+          .map(|key| {
+            let prop_range = lit.get(key).expect("key was found in the map derived from this same section").range.clone();
+            lit
+              .properties
+              .iter()
+              .position(|prop| prop.range == prop_range)
+              .expect("found by lit.get() above")
+          })
+          .collect();
+
+        if remove_indices.len() == lit.properties.len() {
+          // every entry in the section was removed - drop the whole
+          // "imports"/"dependencies" property instead of leaving `{}`
+          let prop_index = obj
+            .properties
+            .iter()
+            .position(|prop| match &prop.value {
+              Value::Object(o) => o.range == lit.range,
+              _ => false,
+            })
+            .expect("found by obj.get() above");
+          emptied_section_indices.push(prop_index);
+        } else {
+          text_changes.extend(remove_properties_text_changes(
+            lit,
+            &remove_indices,
+            config_file_contents,
+          ));
+        }
+      }
+      // nothing to remove if the section was never there to begin with
+      None => {}
+      // we verified the shape of `imports`/`dependencies` above
+      Some(_) => unreachable!(),
+    }
   }
 
+  text_changes.extend(remove_properties_text_changes(
+    obj,
+    &emptied_section_indices,
+    config_file_contents,
+  ));
+
   let new_text =
     deno_ast::apply_text_changes(config_file_contents, text_changes);
 
@@ -456,3 +840,180 @@ fn update_config_file_content(
   .map(|formatted_text| formatted_text.unwrap_or_else(|| new_text.clone()))
   .unwrap_or(new_text)
 }
+
+/// Finds the byte offset of the first `,` in `text` at or after `start`
+/// that isn't inside a `//` or `/* */` comment.
+///
+/// A plain `str::find(',')` would also match a comma embedded in a
+/// same-line trailing comment (e.g. `// note, with detail`), which sits
+/// before the real separator and isn't the JSON delimiter.
+fn find_separator_comma(text: &str, start: usize) -> Option<usize> {
+  let bytes = text.as_bytes();
+  let mut i = start;
+  while i < bytes.len() {
+    match bytes[i] {
+      b',' => return Some(i),
+      b'/' if bytes.get(i + 1) == Some(&b'/') => {
+        i += 2;
+        while i < bytes.len() && bytes[i] != b'\n' {
+          i += 1;
+        }
+      }
+      b'/' if bytes.get(i + 1) == Some(&b'*') => {
+        i += 2;
+        while i + 1 < bytes.len() && !(bytes[i] == b'*' && bytes[i + 1] == b'/') {
+          i += 1;
+        }
+        i += 2;
+      }
+      _ => i += 1,
+    }
+  }
+  None
+}
+
+#[cfg(test)]
+mod find_separator_comma_tests {
+  use super::find_separator_comma;
+
+  #[test]
+  fn finds_the_immediately_following_comma() {
+    assert_eq!(find_separator_comma("\"1\", \"b\"", 0), Some(3));
+  }
+
+  #[test]
+  fn skips_a_comma_inside_a_line_comment() {
+    assert_eq!(
+      find_separator_comma("\"1\" // note, with detail\n, \"b\"", 0),
+      Some(25)
+    );
+  }
+
+  #[test]
+  fn skips_a_comma_inside_a_block_comment() {
+    assert_eq!(
+      find_separator_comma("\"1\" /* note, with comma */, \"b\"", 0),
+      Some(26)
+    );
+  }
+
+  #[test]
+  fn returns_none_when_no_comma_follows() {
+    assert_eq!(find_separator_comma("\"1\" // trailing comment", 0), None);
+  }
+}
+
+/// Builds the `TextChange`s that delete the given property indices from
+/// `obj`, consuming whichever adjacent comma keeps the rest valid JSON.
+///
+/// Only the comma immediately following the removed run is consumed, not
+/// the full span up to the next surviving property - `jsonc_parser` ranges
+/// don't include comment trivia, so a comment documenting the next
+/// surviving property can sit in that gap and must be left alone.
+fn remove_properties_text_changes(
+  obj: &jsonc_parser::ast::Object,
+  indices: &[usize],
+  config_file_contents: &str,
+) -> Vec<TextChange> {
+  let indices: std::collections::HashSet<usize> =
+    indices.iter().copied().collect();
+  let len = obj.properties.len();
+  let mut text_changes = vec![];
+  let mut i = 0;
+
+  while i < len {
+    if !indices.contains(&i) {
+      i += 1;
+      continue;
+    }
+    let run_start = i;
+    while i < len && indices.contains(&i) {
+      i += 1;
+    }
+    let run_end = i; // exclusive
+
+    let start = if run_start == 0 {
+      obj.range.start + 1
+    } else if run_end < len {
+      // the following comma is consumed below, so keep the preceding one
+      let prev_end = obj.properties[run_start - 1].range.end;
+      find_separator_comma(config_file_contents, prev_end)
+        .map(|pos| pos + 1)
+        .unwrap_or(prev_end)
+    } else {
+      obj.properties[run_start - 1].range.end
+    };
+    let run_last_end = obj.properties[run_end - 1].range.end;
+    let end = if run_end < len {
+      match find_separator_comma(config_file_contents, run_last_end) {
+        Some(pos) => pos + 1,
+        // no comma before the next property (shouldn't normally happen) -
+        // fall back to its start so we still produce valid JSON
+        None => obj.properties[run_end].range.start,
+      }
+    } else {
+      run_last_end
+    };
+    text_changes.push(TextChange {
+      range: start..end,
+      new_text: String::new(),
+    });
+  }
+
+  text_changes
+}
+
+#[cfg(test)]
+mod remove_properties_text_changes_tests {
+  use jsonc_parser::ast::Value;
+
+  use super::remove_properties_text_changes;
+
+  fn parse_object(text: &str) -> jsonc_parser::ast::Object {
+    let ast =
+      jsonc_parser::parse_to_ast(text, &Default::default(), &Default::default())
+        .unwrap();
+    match ast.value {
+      Some(Value::Object(obj)) => obj,
+      _ => panic!("expected an object"),
+    }
+  }
+
+  #[test]
+  fn preserves_comment_documenting_the_next_surviving_property() {
+    let text = "{\"a\": \"1\", // keep this\n  \"b\": \"2\"}";
+    let obj = parse_object(text);
+    let changes = remove_properties_text_changes(&obj, &[0], text);
+    let new_text = deno_ast::apply_text_changes(text, changes);
+    assert!(new_text.contains("// keep this"));
+    assert!(new_text.contains("\"b\": \"2\""));
+    assert!(!new_text.contains("\"a\""));
+  }
+
+  #[test]
+  fn drops_the_adjacent_comma_when_removing_the_last_property() {
+    let text = "{\"a\": \"1\", \"b\": \"2\"}";
+    let obj = parse_object(text);
+    let changes = remove_properties_text_changes(&obj, &[1], text);
+    let new_text = deno_ast::apply_text_changes(text, changes);
+    assert_eq!(new_text, "{\"a\": \"1\"}");
+  }
+
+  #[test]
+  fn removes_every_property_leaving_an_empty_object() {
+    let text = "{\"a\": \"1\", \"b\": \"2\"}";
+    let obj = parse_object(text);
+    let changes = remove_properties_text_changes(&obj, &[0, 1], text);
+    let new_text = deno_ast::apply_text_changes(text, changes);
+    assert_eq!(new_text, "{}");
+  }
+
+  #[test]
+  fn skips_a_comma_embedded_in_a_trailing_comment() {
+    let text = "{\"a\": \"1\" /* note, with comma */, \"b\": \"2\"}";
+    let obj = parse_object(text);
+    let changes = remove_properties_text_changes(&obj, &[0], text);
+    let new_text = deno_ast::apply_text_changes(text, changes);
+    assert_eq!(new_text, "{ \"b\": \"2\"}");
+  }
+}